@@ -1,8 +1,10 @@
 use crate::chunk_type::ChunkType;
+use crate::value::Value;
 use crate::{Error, Result};
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 use crc::{crc32, Hasher32};
-use std::convert::TryInto;
-use std::io::{Cursor, Read};
+use std::convert::{TryFrom, TryInto};
+use std::io::{self, Cursor, Read, Write};
 
 /// Parse a chunk from bytes as described by the specifications of PNG files
 /// ([PNG Structure](http://www.libpng.org/pub/png/spec/1.2/PNG-Structure.html)).
@@ -45,8 +47,81 @@ use std::io::{Cursor, Read};
 pub struct Chunk {
     length: u32, // NOTE: this must not exceed 2^31
     chunk_type: ChunkType,
-    chunk_data: Vec<u8>,
+    chunk_data: Bytes,
     crc: u32,
+    crc_valid: bool,
+}
+
+/// Which CRC-32 polynomial to use when computing or verifying a chunk's
+/// checksum.
+///
+/// Defaults to [`CrcVariant::Ieee`], the polynomial the PNG specification
+/// itself mandates; the other variants exist for reading forensic captures
+/// or test fixtures produced with a different one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrcVariant {
+    #[default]
+    Ieee,
+    Castagnoli,
+    Koopman,
+}
+
+impl CrcVariant {
+    fn polynomial(self) -> u32 {
+        match self {
+            CrcVariant::Ieee => crc32::IEEE,
+            CrcVariant::Castagnoli => crc32::CASTAGNOLI,
+            CrcVariant::Koopman => crc32::KOOPMAN,
+        }
+    }
+}
+
+/// How strictly a chunk's stored CRC is checked against the one computed
+/// while parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidationMode {
+    /// Reject the chunk if the stored and computed CRCs disagree (the
+    /// default, and the only behavior available before `ChunkOptions`
+    /// existed).
+    #[default]
+    Strict,
+    /// Keep the stored CRC and parse successfully even on a mismatch;
+    /// callers can check [`Chunk::crc_is_valid`] themselves.
+    Lenient,
+    /// Ignore the stored CRC entirely and replace it with the one computed
+    /// from the chunk's type and data.
+    Recompute,
+}
+
+/// Options controlling how a chunk's CRC is computed and validated, for
+/// [`Chunk::new_with_options`] and [`Chunk::try_from_with_options`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChunkOptions {
+    crc_variant: CrcVariant,
+    validation_mode: ValidationMode,
+}
+
+impl ChunkOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn crc_variant(mut self, crc_variant: CrcVariant) -> Self {
+        self.crc_variant = crc_variant;
+        self
+    }
+
+    pub fn validation_mode(mut self, validation_mode: ValidationMode) -> Self {
+        self.validation_mode = validation_mode;
+        self
+    }
+}
+
+fn compute_crc(crc_variant: CrcVariant, chunk_type: &ChunkType, chunk_data: &[u8]) -> u32 {
+    let mut digest = crc32::Digest::new(crc_variant.polynomial());
+    digest.write(&chunk_type.bytes());
+    digest.write(chunk_data);
+    digest.sum32()
 }
 
 impl Chunk {
@@ -73,20 +148,39 @@ impl Chunk {
     /// # }
     /// ```
     pub fn new(chunk_type: ChunkType, chunk_data: Vec<u8>) -> Result<Self> {
+        Self::from_data(chunk_type, Bytes::from(chunk_data))
+    }
+
+    /// Create a new chunk from the given chunk type and a [`Bytes`] view of
+    /// its data.
+    ///
+    /// Unlike [`Chunk::new`], which takes ownership of a freshly allocated
+    /// `Vec<u8>`, this accepts an existing `Bytes` — typically a cheap,
+    /// refcounted slice of a larger buffer — so building a chunk around a
+    /// large embedded payload doesn't require copying it first.
+    pub fn from_data(chunk_type: ChunkType, chunk_data: Bytes) -> Result<Self> {
+        Self::new_with_options(chunk_type, chunk_data, ChunkOptions::default())
+    }
+
+    /// Create a new chunk, computing its CRC with the polynomial selected by
+    /// `options`.
+    pub fn new_with_options(
+        chunk_type: ChunkType,
+        chunk_data: Bytes,
+        options: ChunkOptions,
+    ) -> Result<Self> {
         if chunk_data.len() > 1 << 31 {
             return Err(Error::InvalidChunkLength);
         }
 
-        // creating checksum from received data
-        let mut digest = crc32::Digest::new(crc32::IEEE);
-        digest.write(&chunk_type.bytes());
-        digest.write(&chunk_data);
+        let crc = compute_crc(options.crc_variant, &chunk_type, &chunk_data);
 
         Ok(Self {
             length: chunk_data.len().try_into()?,
             chunk_type,
             chunk_data,
-            crc: digest.sum32(),
+            crc,
+            crc_valid: true,
         })
     }
 
@@ -214,6 +308,18 @@ impl Chunk {
         self.crc
     }
 
+    /// Whether the CRC this chunk was parsed with matched the one computed
+    /// from its type and data.
+    ///
+    /// Always `true` for a chunk built with [`Chunk::new`] or parsed under
+    /// [`ValidationMode::Strict`] (the default), since a mismatch there is
+    /// an error rather than a value to inspect. Meaningful mainly after
+    /// parsing with [`ValidationMode::Lenient`], which stores tampering
+    /// evidence here instead of rejecting the chunk outright.
+    pub fn crc_is_valid(&self) -> bool {
+        self.crc_valid
+    }
+
     /// Get the data of the chunk encoded as an UTF-8 string
     /// # Examples
     ///
@@ -242,7 +348,15 @@ impl Chunk {
     ///     Ok(())
     /// # }
     pub fn data_as_string(&self) -> Result<String> {
-        Ok(String::from_utf8(self.chunk_data.clone())?)
+        Ok(String::from_utf8(self.chunk_data.to_vec())?)
+    }
+
+    /// Get a cheap, refcounted view of the chunk's data.
+    ///
+    /// Cloning the returned [`Bytes`] only bumps a reference count; it does
+    /// not copy the underlying buffer.
+    pub fn data_bytes(&self) -> Bytes {
+        self.chunk_data.clone()
     }
 
     /// Get the whole chunk in bytes
@@ -273,14 +387,18 @@ impl Chunk {
     ///     Ok(())
     /// # }
     pub fn as_bytes(&self) -> Vec<u8> {
-        self.length
-            .to_be_bytes()
-            .iter()
-            .chain(self.chunk_type.bytes().iter())
-            .chain(self.chunk_data.iter())
-            .chain(self.crc.to_be_bytes().iter())
-            .copied()
-            .collect()
+        let mut buf = BytesMut::with_capacity(12 + self.chunk_data.len());
+        self.encode_to(&mut buf);
+        buf.to_vec()
+    }
+
+    /// Serialize the chunk's length, type, data and CRC into `buf`, growing
+    /// it as needed but without any other intermediate allocation.
+    pub fn encode_to(&self, buf: &mut BytesMut) {
+        buf.put_u32(self.length);
+        buf.put_slice(&self.chunk_type.bytes());
+        buf.put_slice(&self.chunk_data);
+        buf.put_u32(self.crc);
     }
 }
 
@@ -299,6 +417,15 @@ impl std::convert::TryFrom<&[u8]> for Chunk {
     type Error = Error;
 
     fn try_from(raw: &[u8]) -> Result<Self> {
+        Self::try_from_with_options(raw, ChunkOptions::default())
+    }
+}
+
+impl Chunk {
+    /// Parse a chunk from bytes, computing and validating its CRC as
+    /// directed by `options` instead of always assuming
+    /// `CrcVariant::Ieee` + `ValidationMode::Strict`.
+    pub fn try_from_with_options(raw: &[u8], options: ChunkOptions) -> Result<Self> {
         let mut r = Cursor::new(raw);
         let mut buf = [0u8; 4];
 
@@ -306,7 +433,7 @@ impl std::convert::TryFrom<&[u8]> for Chunk {
         r.read_exact(&mut buf)?;
         let length = u32::from_be_bytes(buf);
         if length > 1 << 31 {
-            return Err(Self::Error::InvalidChunkLength);
+            return Err(Error::InvalidChunkLength);
         }
 
         // parse chunk type
@@ -317,27 +444,301 @@ impl std::convert::TryFrom<&[u8]> for Chunk {
         let mut chunk_data = vec![0; length.try_into()?];
         r.read_exact(&mut chunk_data)?;
 
-        // creating checksum from received data
-        let mut digest = crc32::Digest::new(crc32::IEEE);
-        digest.write(&buf);
-        digest.write(&chunk_data);
+        let expected_crc = compute_crc(options.crc_variant, &chunk_type, &chunk_data);
 
-        // parse and check chunk checksum
+        // parse chunk checksum
         r.read_exact(&mut buf)?;
-        let crc = u32::from_be_bytes(buf);
-        if digest.sum32() != crc {
-            return Err(Self::Error::InvalidCRC);
-        }
+        let stored_crc = u32::from_be_bytes(buf);
+        let crc_is_valid = stored_crc == expected_crc;
+
+        let (crc, crc_valid) = match options.validation_mode {
+            ValidationMode::Strict if !crc_is_valid => return Err(Error::InvalidCRC),
+            ValidationMode::Strict | ValidationMode::Lenient => (stored_crc, crc_is_valid),
+            ValidationMode::Recompute => (expected_crc, true),
+        };
 
         Ok(Self {
             length,
             chunk_type,
-            chunk_data,
+            chunk_data: Bytes::from(chunk_data),
+            crc,
+            crc_valid,
+        })
+    }
+}
+
+impl Chunk {
+    /// Parse one chunk off the front of `raw`, returning the chunk and the
+    /// remaining, unconsumed bytes.
+    ///
+    /// Because `raw` is an owned, refcounted [`Bytes`], the chunk's data is
+    /// split off as a cheap view into the same underlying buffer instead of
+    /// being copied — unlike parsing from a borrowed `&[u8]`, which must copy
+    /// the data out before it can build an owned `Chunk`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use pmsg::Chunk;
+    /// # use bytes::Bytes;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///     let data_length: u32 = 14;
+    ///     let chunk_type = b"bLOb";
+    ///     let chunk_data = b"THE CHUNK DATA";
+    ///     let crc: u32 = 4148869028;
+    ///
+    ///     let raw_chunk: Vec<u8> = data_length
+    ///         .to_be_bytes()
+    ///         .iter()
+    ///         .chain(chunk_type.iter())
+    ///         .chain(chunk_data.iter())
+    ///         .chain(crc.to_be_bytes().iter())
+    ///         .copied()
+    ///         .collect();
+    ///
+    ///     let (chunk, rest) = Chunk::try_from_bytes(Bytes::from(raw_chunk))?;
+    ///     assert_eq!(chunk.data(), chunk_data);
+    ///     assert!(rest.is_empty());
+    ///     Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_bytes(raw: Bytes) -> Result<(Self, Bytes)> {
+        Self::try_from_bytes_with_options(raw, ChunkOptions::default())
+    }
+
+    /// Parse one chunk off the front of `raw`, as [`Chunk::try_from_bytes`],
+    /// computing and validating its CRC as directed by `options`.
+    pub fn try_from_bytes_with_options(
+        mut raw: Bytes,
+        options: ChunkOptions,
+    ) -> Result<(Self, Bytes)> {
+        if raw.len() < 8 {
+            return Err(Self::truncated_chunk_error());
+        }
+        let length = raw.get_u32();
+        if length > 1 << 31 {
+            return Err(Error::InvalidChunkLength);
+        }
+        let length = length as usize;
+
+        // `raw` already had its 4-byte length field consumed above, so what
+        // remains must cover the type (4), the data (`length`), and the CRC
+        // (4).
+        if raw.len() < length + 8 {
+            return Err(Self::truncated_chunk_error());
+        }
+
+        let mut type_buf = [0u8; 4];
+        raw.copy_to_slice(&mut type_buf);
+        let chunk_type = ChunkType::try_from(type_buf)?;
+
+        let chunk_data = raw.split_to(length);
+        let expected_crc = compute_crc(options.crc_variant, &chunk_type, &chunk_data);
+
+        let stored_crc = raw.get_u32();
+        let crc_is_valid = stored_crc == expected_crc;
+
+        let (crc, crc_valid) = match options.validation_mode {
+            ValidationMode::Strict if !crc_is_valid => return Err(Error::InvalidCRC),
+            ValidationMode::Strict | ValidationMode::Lenient => (stored_crc, crc_is_valid),
+            ValidationMode::Recompute => (expected_crc, true),
+        };
+
+        Ok((
+            Self {
+                length: length.try_into()?,
+                chunk_type,
+                chunk_data,
+                crc,
+                crc_valid,
+            },
+            raw,
+        ))
+    }
+
+    fn truncated_chunk_error() -> Error {
+        io::Error::new(io::ErrorKind::UnexpectedEof, "truncated chunk").into()
+    }
+}
+
+impl Chunk {
+    /// Create a chunk whose data is a self-describing [`Value`], so it can
+    /// carry structured metadata (integers, byte strings, lists, ...)
+    /// instead of only plain UTF-8 text.
+    pub fn new_typed(chunk_type: ChunkType, value: &Value) -> Result<Self> {
+        Self::new(chunk_type, crate::value::encode(value))
+    }
+
+    /// Parse the chunk's data back into a [`Value`].
+    pub fn decode_value(&self) -> Result<Value> {
+        crate::value::decode(&self.chunk_data)
+    }
+}
+
+/// Reads a sequence of chunks out of any [`Read`] source, one chunk at a time.
+///
+/// Unlike [`Chunk::try_from`], which needs the whole chunk (and typically the
+/// whole file) buffered in memory up front, `ChunkReader` only ever buffers a
+/// single chunk's data at a time: it reads the 4-byte length, the 4-byte type,
+/// exactly `length` data bytes and the 4-byte CRC, verifying the checksum as
+/// it goes. This makes it possible to stream through a multi-megabyte PNG
+/// without holding the whole file in memory.
+///
+/// Iteration stops (returning `None`) when the underlying reader is
+/// exhausted exactly at a chunk boundary. A short read in the middle of a
+/// chunk is reported as `Some(Err(_))`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use pmsg::ChunkReader;
+/// # use std::io::Cursor;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let data_length: u32 = 14;
+///     let chunk_type = b"bLOb";
+///     let chunk_data = b"THE CHUNK DATA";
+///     let crc: u32 = 4148869028;
+///
+///     let raw_chunk: Vec<u8> = data_length
+///         .to_be_bytes()
+///         .iter()
+///         .chain(chunk_type.iter())
+///         .chain(chunk_data.iter())
+///         .chain(crc.to_be_bytes().iter())
+///         .copied()
+///         .collect();
+///
+///     let mut reader = ChunkReader::new(Cursor::new(raw_chunk));
+///     let chunk = reader.next().unwrap()?;
+///     assert_eq!(chunk.length(), data_length);
+///     assert!(reader.next().is_none());
+///     Ok(())
+/// # }
+/// ```
+pub struct ChunkReader<R> {
+    inner: R,
+    options: ChunkOptions,
+}
+
+impl<R: Read> ChunkReader<R> {
+    /// Wrap `inner` so that chunks can be pulled out of it one at a time,
+    /// using the default [`ChunkOptions`] (IEEE CRC-32, strict validation).
+    pub fn new(inner: R) -> Self {
+        Self::with_options(inner, ChunkOptions::default())
+    }
+
+    /// Wrap `inner`, computing and validating each chunk's CRC as directed
+    /// by `options` — e.g. `ValidationMode::Lenient` to read deliberately
+    /// corrupted test images, or `ValidationMode::Recompute` to stamp fresh
+    /// checksums while streaming a file through.
+    pub fn with_options(inner: R, options: ChunkOptions) -> Self {
+        Self { inner, options }
+    }
+
+    fn read_chunk(&mut self, length_bytes: [u8; 4]) -> Result<Chunk> {
+        let length = u32::from_be_bytes(length_bytes);
+        if length > 1 << 31 {
+            return Err(Error::InvalidChunkLength);
+        }
+
+        let mut type_buf = [0u8; 4];
+        self.inner.read_exact(&mut type_buf)?;
+        let chunk_type = ChunkType::try_from(type_buf)?;
+
+        let mut chunk_data = vec![0; length.try_into()?];
+        self.inner.read_exact(&mut chunk_data)?;
+
+        let expected_crc = compute_crc(self.options.crc_variant, &chunk_type, &chunk_data);
+
+        let mut crc_buf = [0u8; 4];
+        self.inner.read_exact(&mut crc_buf)?;
+        let stored_crc = u32::from_be_bytes(crc_buf);
+        let crc_is_valid = stored_crc == expected_crc;
+
+        let (crc, crc_valid) = match self.options.validation_mode {
+            ValidationMode::Strict if !crc_is_valid => return Err(Error::InvalidCRC),
+            ValidationMode::Strict | ValidationMode::Lenient => (stored_crc, crc_is_valid),
+            ValidationMode::Recompute => (expected_crc, true),
+        };
+
+        Ok(Chunk {
+            length,
+            chunk_type,
+            chunk_data: Bytes::from(chunk_data),
             crc,
+            crc_valid,
         })
     }
 }
 
+impl<R: Read> Iterator for ChunkReader<R> {
+    type Item = Result<Chunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut length_bytes = [0u8; 4];
+        let mut filled = 0;
+        while filled < length_bytes.len() {
+            match self.inner.read(&mut length_bytes[filled..]) {
+                // End of input before any byte of the next chunk: a clean end.
+                Ok(0) if filled == 0 => return None,
+                Ok(0) => return Some(Err(Chunk::truncated_chunk_error())),
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+        Some(self.read_chunk(length_bytes))
+    }
+}
+
+/// Serializes chunks one at a time into any [`Write`] sink.
+///
+/// This is the write-side companion of [`ChunkReader`]: it lets a pipeline
+/// copy a PNG chunk-by-chunk, inserting or removing hidden chunks along the
+/// way, without ever buffering the whole output image.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use pmsg::{Chunk, ChunkType, ChunkWriter};
+/// # use std::convert::TryFrom;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let chunk = Chunk::new(ChunkType::try_from(*b"bLOb")?, b"THE CHUNK DATA".to_vec())?;
+///     let mut out = Vec::new();
+///     let mut writer = ChunkWriter::new(&mut out);
+///     writer.write_chunk(&chunk)?;
+///     assert_eq!(out, chunk.as_bytes());
+///     Ok(())
+/// # }
+/// ```
+pub struct ChunkWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> ChunkWriter<W> {
+    /// Wrap `inner` so that chunks can be written to it one at a time.
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Serialize `chunk` and write it to the underlying sink.
+    pub fn write_chunk(&mut self, chunk: &Chunk) -> Result<()> {
+        self.inner.write_all(&chunk.as_bytes())?;
+        Ok(())
+    }
+
+    /// Consume the writer, returning the wrapped sink.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -435,6 +836,236 @@ mod tests {
         assert!(chunk.is_err());
     }
 
+    #[test]
+    fn test_chunk_options_lenient_keeps_stale_crc_without_error() {
+        let data_length: u32 = 42;
+        let chunk_type = b"RuSt";
+        let message_bytes = b"This is where your secret message will be!";
+        let stale_crc: u32 = 2882656333;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(stale_crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let options = ChunkOptions::new().validation_mode(ValidationMode::Lenient);
+        let chunk = Chunk::try_from_with_options(chunk_data.as_ref(), options).unwrap();
+
+        assert_eq!(chunk.crc(), stale_crc);
+        assert!(!chunk.crc_is_valid());
+    }
+
+    #[test]
+    fn test_chunk_options_recompute_ignores_stored_crc() {
+        let data_length: u32 = 42;
+        let chunk_type = b"RuSt";
+        let message_bytes = b"This is where your secret message will be!";
+        let stale_crc: u32 = 2882656333;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(stale_crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let options = ChunkOptions::new().validation_mode(ValidationMode::Recompute);
+        let chunk = Chunk::try_from_with_options(chunk_data.as_ref(), options).unwrap();
+
+        assert_ne!(chunk.crc(), stale_crc);
+        assert!(chunk.crc_is_valid());
+    }
+
+    #[test]
+    fn test_chunk_options_strict_still_errors_on_mismatch() {
+        let chunk = testing_chunk();
+        assert!(chunk.crc_is_valid());
+
+        let data_length: u32 = 42;
+        let chunk_type = b"RuSt";
+        let message_bytes = b"This is where your secret message will be!";
+        let stale_crc: u32 = 2882656333;
+
+        let chunk_data: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(stale_crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let options = ChunkOptions::new().validation_mode(ValidationMode::Strict);
+        assert!(Chunk::try_from_with_options(chunk_data.as_ref(), options).is_err());
+    }
+
+    #[test]
+    fn test_chunk_options_custom_crc_variant_round_trips() {
+        let chunk_type = ChunkType::try_from(*b"RuSt").unwrap();
+        let options = ChunkOptions::new().crc_variant(CrcVariant::Castagnoli);
+
+        let chunk =
+            Chunk::new_with_options(chunk_type, Bytes::from_static(b"hello"), options).unwrap();
+        let raw = chunk.as_bytes();
+
+        let parsed = Chunk::try_from_with_options(raw.as_ref(), options).unwrap();
+        assert!(parsed.crc_is_valid());
+        assert_eq!(parsed.crc(), chunk.crc());
+    }
+
+    #[test]
+    fn test_chunk_new_typed_round_trips_value() {
+        let chunk_type = ChunkType::try_from(*b"vALu").unwrap();
+        let value = Value::List(vec![Value::Int(7), Value::Str("hi".into())]);
+
+        let chunk = Chunk::new_typed(chunk_type, &value).unwrap();
+        assert_eq!(chunk.decode_value().unwrap(), value);
+    }
+
+    #[test]
+    fn test_chunk_data_bytes_is_a_cheap_view() {
+        let chunk = testing_chunk();
+        let data = chunk.data_bytes();
+        assert_eq!(data.as_ref(), chunk.data());
+        assert_eq!(data.as_ptr(), chunk.data().as_ptr());
+    }
+
+    #[test]
+    fn test_chunk_try_from_bytes_round_trip() {
+        let chunk = testing_chunk();
+        let raw = chunk.as_bytes();
+
+        let (parsed, rest) = Chunk::try_from_bytes(bytes::Bytes::from(raw)).unwrap();
+        assert_eq!(parsed.data(), chunk.data());
+        assert_eq!(parsed.crc(), chunk.crc());
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_chunk_try_from_bytes_leaves_trailing_bytes() {
+        let chunk = testing_chunk();
+        let mut raw = chunk.as_bytes();
+        raw.extend_from_slice(b"trailing");
+
+        let (_parsed, rest) = Chunk::try_from_bytes(bytes::Bytes::from(raw)).unwrap();
+        assert_eq!(rest.as_ref(), b"trailing");
+    }
+
+    #[test]
+    fn test_chunk_try_from_bytes_errors_on_truncated_chunk() {
+        let chunk = testing_chunk();
+        let mut raw = chunk.as_bytes();
+        raw.truncate(raw.len() - 1);
+
+        assert!(Chunk::try_from_bytes(bytes::Bytes::from(raw)).is_err());
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_single_chunk() {
+        let chunk = testing_chunk();
+        let raw = chunk.as_bytes();
+
+        let mut reader = ChunkReader::new(Cursor::new(raw));
+        let read_chunk = reader.next().unwrap().unwrap();
+
+        assert_eq!(read_chunk.length(), chunk.length());
+        assert_eq!(read_chunk.chunk_type(), chunk.chunk_type());
+        assert_eq!(read_chunk.data(), chunk.data());
+        assert_eq!(read_chunk.crc(), chunk.crc());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_reads_multiple_chunks() {
+        let first = testing_chunk();
+        let second = Chunk::try_from(first.as_bytes().as_ref()).unwrap();
+
+        let mut raw = first.as_bytes();
+        raw.extend(second.as_bytes());
+
+        let mut reader = ChunkReader::new(Cursor::new(raw));
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_chunk_reader_errors_on_truncated_chunk() {
+        let chunk = testing_chunk();
+        let mut raw = chunk.as_bytes();
+        raw.truncate(raw.len() - 1);
+
+        let mut reader = ChunkReader::new(Cursor::new(raw));
+        assert!(reader.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_chunk_reader_with_lenient_options_reads_corrupted_crc() {
+        let data_length: u32 = 42;
+        let chunk_type = b"RuSt";
+        let message_bytes = b"This is where your secret message will be!";
+        let stale_crc: u32 = 2882656333;
+
+        let raw: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(stale_crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let options = ChunkOptions::new().validation_mode(ValidationMode::Lenient);
+        let mut reader = ChunkReader::with_options(Cursor::new(raw), options);
+
+        let chunk = reader.next().unwrap().unwrap();
+        assert_eq!(chunk.crc(), stale_crc);
+        assert!(!chunk.crc_is_valid());
+    }
+
+    #[test]
+    fn test_chunk_try_from_bytes_with_options_recomputes_crc() {
+        let data_length: u32 = 42;
+        let chunk_type = b"RuSt";
+        let message_bytes = b"This is where your secret message will be!";
+        let stale_crc: u32 = 2882656333;
+
+        let raw: Vec<u8> = data_length
+            .to_be_bytes()
+            .iter()
+            .chain(chunk_type.iter())
+            .chain(message_bytes.iter())
+            .chain(stale_crc.to_be_bytes().iter())
+            .copied()
+            .collect();
+
+        let options = ChunkOptions::new().validation_mode(ValidationMode::Recompute);
+        let (chunk, _rest) =
+            Chunk::try_from_bytes_with_options(bytes::Bytes::from(raw), options).unwrap();
+
+        assert_ne!(chunk.crc(), stale_crc);
+        assert!(chunk.crc_is_valid());
+    }
+
+    #[test]
+    fn test_chunk_writer_round_trips_through_chunk_reader() {
+        let chunk = testing_chunk();
+        let mut out = Vec::new();
+
+        let mut writer = ChunkWriter::new(&mut out);
+        writer.write_chunk(&chunk).unwrap();
+
+        let mut reader = ChunkReader::new(Cursor::new(out));
+        let read_chunk = reader.next().unwrap().unwrap();
+        assert_eq!(read_chunk.data(), chunk.data());
+    }
+
     #[test]
     pub fn test_chunk_trait_impls() {
         let data_length: u32 = 42;
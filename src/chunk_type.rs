@@ -0,0 +1,134 @@
+use crate::{Error, Result};
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::str::{self, FromStr};
+
+/// A PNG chunk type code: four bytes, each an ASCII letter, encoding a few
+/// bits of meaning in their case
+/// ([Chunk naming conventions](http://www.libpng.org/pub/png/spec/1.2/PNG-Chunks.html#C.Chunk-naming-conventions)).
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct ChunkType {
+    bytes: [u8; 4],
+}
+
+impl ChunkType {
+    /// Get the four raw bytes of the type code.
+    pub fn bytes(&self) -> [u8; 4] {
+        self.bytes
+    }
+
+    /// A type code is valid if every byte is an ASCII letter and the
+    /// reserved bit (bit 5 of the third byte) is unset.
+    pub fn is_valid(&self) -> bool {
+        self.is_reserved_bit_valid() && self.bytes.iter().all(|b| b.is_ascii_alphabetic())
+    }
+
+    /// Whether an image decoder must understand this chunk to render the
+    /// image correctly (bit 5 of the first byte unset).
+    pub fn is_critical(&self) -> bool {
+        self.bytes[0] & 0x20 == 0
+    }
+
+    /// Whether this chunk type is part of the public PNG specification, as
+    /// opposed to a private, application-specific one (bit 5 of the second
+    /// byte unset).
+    pub fn is_public(&self) -> bool {
+        self.bytes[1] & 0x20 == 0
+    }
+
+    /// Whether the reserved bit (bit 5 of the third byte) is unset, as the
+    /// current PNG specification requires.
+    pub fn is_reserved_bit_valid(&self) -> bool {
+        self.bytes[2] & 0x20 == 0
+    }
+
+    /// Whether an editor that doesn't understand this chunk may safely copy
+    /// it unchanged (bit 5 of the fourth byte set).
+    pub fn is_safe_to_copy(&self) -> bool {
+        self.bytes[3] & 0x20 != 0
+    }
+}
+
+impl TryFrom<[u8; 4]> for ChunkType {
+    type Error = Error;
+
+    fn try_from(bytes: [u8; 4]) -> Result<Self> {
+        if !bytes.iter().all(|b| b.is_ascii_alphabetic()) {
+            return Err(Error::InvalidChunkType);
+        }
+        Ok(Self { bytes })
+    }
+}
+
+impl FromStr for ChunkType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let bytes: [u8; 4] = s.as_bytes().try_into().map_err(|_| Error::InvalidChunkType)?;
+        Self::try_from(bytes)
+    }
+}
+
+impl fmt::Display for ChunkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", str::from_utf8(&self.bytes).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_type_from_bytes() {
+        let chunk_type = ChunkType::try_from(*b"RuSt").unwrap();
+        assert_eq!(chunk_type.bytes(), *b"RuSt");
+    }
+
+    #[test]
+    fn test_chunk_type_from_str() {
+        let chunk_type = ChunkType::from_str("RuSt").unwrap();
+        assert_eq!(chunk_type.bytes(), *b"RuSt");
+    }
+
+    #[test]
+    fn test_chunk_type_display() {
+        let chunk_type = ChunkType::try_from(*b"RuSt").unwrap();
+        assert_eq!(chunk_type.to_string(), "RuSt");
+    }
+
+    #[test]
+    fn test_chunk_type_rejects_non_alphabetic_bytes() {
+        assert!(ChunkType::try_from(*b"Ru1t").is_err());
+    }
+
+    #[test]
+    fn test_chunk_type_is_critical() {
+        assert!(ChunkType::try_from(*b"RuSt").unwrap().is_critical());
+        assert!(!ChunkType::try_from(*b"ruSt").unwrap().is_critical());
+    }
+
+    #[test]
+    fn test_chunk_type_is_public() {
+        assert!(!ChunkType::try_from(*b"RuSt").unwrap().is_public());
+        assert!(ChunkType::try_from(*b"RUSt").unwrap().is_public());
+    }
+
+    #[test]
+    fn test_chunk_type_is_reserved_bit_valid() {
+        assert!(ChunkType::try_from(*b"RuSt").unwrap().is_reserved_bit_valid());
+        assert!(!ChunkType::try_from(*b"Rust").unwrap().is_reserved_bit_valid());
+    }
+
+    #[test]
+    fn test_chunk_type_is_safe_to_copy() {
+        assert!(ChunkType::try_from(*b"RuSt").unwrap().is_safe_to_copy());
+        assert!(!ChunkType::try_from(*b"RuST").unwrap().is_safe_to_copy());
+    }
+
+    #[test]
+    fn test_chunk_type_valid() {
+        assert!(ChunkType::try_from(*b"RuSt").unwrap().is_valid());
+        assert!(!ChunkType::try_from(*b"Rust").unwrap().is_valid());
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+use std::num::TryFromIntError;
+use std::string::FromUtf8Error;
+
+/// The error type shared across this crate.
+#[derive(Debug)]
+pub enum Error {
+    /// A chunk's data length exceeds the 2^31 limit the PNG spec allows.
+    InvalidChunkLength,
+    /// A parsed chunk's stored CRC did not match the one computed from its
+    /// type and data.
+    InvalidCRC,
+    /// A chunk type code was not four ASCII letters.
+    InvalidChunkType,
+    /// No chunk of the requested type was found.
+    ChunkNotFound,
+    /// A byte buffer did not start with the PNG signature.
+    InvalidPngHeader,
+    /// `split_payload` was asked for a `max_segment_len` of zero.
+    InvalidSegmentLength,
+    /// Segments disagreed about how many total segments there were.
+    SegmentCountMismatch,
+    /// `reassemble` found no chunks carrying the segment magic.
+    NoSegmentsFound,
+    /// A segment index was missing or duplicated once the segments were
+    /// sorted into order.
+    MissingSegment,
+    /// A `Value` decoded successfully but left unconsumed trailing bytes.
+    TrailingValueBytes,
+    /// A `Value`'s encoding ended before a tag's payload was fully read.
+    TruncatedValue,
+    /// A `Value`'s length prefix or payload didn't match its tag.
+    InvalidValueEncoding,
+    /// A `Value`'s tag byte did not match any known kind.
+    UnknownValueTag,
+    /// An I/O error encountered while reading or writing chunk bytes.
+    Io(std::io::Error),
+    /// A length didn't fit the target integer width.
+    TryFromInt(TryFromIntError),
+    /// Chunk data that was expected to be UTF-8 was not.
+    FromUtf8(FromUtf8Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidChunkLength => write!(f, "invalid chunk length"),
+            Error::InvalidCRC => write!(f, "invalid chunk CRC"),
+            Error::InvalidChunkType => write!(f, "invalid chunk type"),
+            Error::ChunkNotFound => write!(f, "chunk not found"),
+            Error::InvalidPngHeader => write!(f, "invalid PNG header"),
+            Error::InvalidSegmentLength => write!(f, "invalid segment length"),
+            Error::SegmentCountMismatch => write!(f, "segment count mismatch"),
+            Error::NoSegmentsFound => write!(f, "no segments found"),
+            Error::MissingSegment => write!(f, "missing or duplicated segment"),
+            Error::TrailingValueBytes => write!(f, "trailing bytes after value"),
+            Error::TruncatedValue => write!(f, "truncated value"),
+            Error::InvalidValueEncoding => write!(f, "invalid value encoding"),
+            Error::UnknownValueTag => write!(f, "unknown value tag"),
+            Error::Io(e) => write!(f, "{}", e),
+            Error::TryFromInt(e) => write!(f, "{}", e),
+            Error::FromUtf8(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(e: TryFromIntError) -> Self {
+        Error::TryFromInt(e)
+    }
+}
+
+impl From<FromUtf8Error> for Error {
+    fn from(e: FromUtf8Error) -> Self {
+        Error::FromUtf8(e)
+    }
+}
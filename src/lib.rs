@@ -0,0 +1,14 @@
+mod chunk;
+mod chunk_type;
+mod error;
+mod png;
+mod segment;
+pub mod value;
+
+pub use chunk::{Chunk, ChunkOptions, ChunkReader, ChunkWriter, CrcVariant, ValidationMode};
+pub use chunk_type::ChunkType;
+pub use error::Error;
+pub use png::Png;
+pub use segment::{reassemble, split_payload};
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,224 @@
+use crate::chunk::Chunk;
+use crate::{Error, Result};
+use std::convert::TryFrom;
+use std::fmt;
+
+/// The 8-byte signature that every PNG file begins with
+/// ([PNG Structure](http://www.libpng.org/pub/png/spec/1.2/PNG-Structure.html)).
+pub const STANDARD_HEADER: [u8; 8] = [137, 80, 78, 71, 13, 10, 26, 10];
+
+/// An in-memory PNG file: the signature plus the ordered list of chunks that
+/// follow it.
+///
+/// This is the layer above [`Chunk`] that a steganography tool actually
+/// operates on: chunks of a private, ancillary type can be inserted before
+/// `IEND` to hide a message, and later found again by type to reveal it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use pmsg::{Chunk, ChunkType, Png};
+/// # use std::convert::TryFrom;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let iend = Chunk::new(ChunkType::try_from(*b"IEND")?, Vec::new())?;
+///     let png = Png::from_chunks(vec![iend]);
+///     assert_eq!(png.as_bytes(), Png::try_from(png.as_bytes().as_ref())?.as_bytes());
+///     Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Png {
+    chunks: Vec<Chunk>,
+}
+
+impl Png {
+    /// Build a `Png` directly from an ordered list of chunks.
+    pub fn from_chunks(chunks: Vec<Chunk>) -> Self {
+        Self { chunks }
+    }
+
+    /// Append `chunk` as the last chunk of the file.
+    pub fn append_chunk(&mut self, chunk: Chunk) {
+        self.chunks.push(chunk);
+    }
+
+    /// Insert `chunk` immediately before the first chunk of type `chunk_type`.
+    ///
+    /// If no chunk of that type is found, `chunk` is appended at the end.
+    pub fn insert_chunk_before(&mut self, chunk_type: &str, chunk: Chunk) {
+        let position = self
+            .chunks
+            .iter()
+            .position(|c| c.chunk_type().to_string() == chunk_type)
+            .unwrap_or(self.chunks.len());
+        self.chunks.insert(position, chunk);
+    }
+
+    /// Remove and return the first chunk of type `chunk_type`.
+    pub fn remove_first_chunk(&mut self, chunk_type: &str) -> Result<Chunk> {
+        let position = self
+            .chunks
+            .iter()
+            .position(|c| c.chunk_type().to_string() == chunk_type)
+            .ok_or(Error::ChunkNotFound)?;
+        Ok(self.chunks.remove(position))
+    }
+
+    /// Get the first chunk of type `chunk_type`, if any.
+    pub fn chunk_by_type(&self, chunk_type: &str) -> Option<&Chunk> {
+        self.chunks
+            .iter()
+            .find(|c| c.chunk_type().to_string() == chunk_type)
+    }
+
+    /// Get the PNG signature.
+    pub fn header(&self) -> &[u8; 8] {
+        &STANDARD_HEADER
+    }
+
+    /// Get all chunks of the file, in order.
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    /// Serialize the signature and every chunk back into bytes.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        STANDARD_HEADER
+            .iter()
+            .copied()
+            .chain(self.chunks.iter().flat_map(Chunk::as_bytes))
+            .collect()
+    }
+}
+
+impl TryFrom<&[u8]> for Png {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < STANDARD_HEADER.len() {
+            return Err(Error::InvalidPngHeader);
+        }
+
+        let (header, mut rest) = bytes.split_at(STANDARD_HEADER.len());
+        if header != STANDARD_HEADER {
+            return Err(Error::InvalidPngHeader);
+        }
+
+        let mut chunks = Vec::new();
+        loop {
+            let chunk = Chunk::try_from(rest)?;
+            // 4-byte length + 4-byte type + data + 4-byte crc
+            let chunk_size = 12 + chunk.data().len();
+            rest = &rest[chunk_size..];
+
+            let is_end = chunk.chunk_type().to_string() == "IEND";
+            chunks.push(chunk);
+            if is_end {
+                break;
+            }
+        }
+
+        Ok(Self { chunks })
+    }
+}
+
+impl fmt::Display for Png {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Png {{")?;
+        for chunk in &self.chunks {
+            writeln!(f, "  {}", chunk)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn chunk_from_strings(chunk_type: &str, data: &str) -> Result<Chunk> {
+        let chunk_type = ChunkType::from_str(chunk_type)?;
+        let data: Vec<u8> = data.bytes().collect();
+        Chunk::new(chunk_type, data)
+    }
+
+    fn testing_png() -> Png {
+        let chunks = vec![
+            chunk_from_strings("FrSt", "I am the first chunk").unwrap(),
+            chunk_from_strings("miDl", "I am another chunk").unwrap(),
+            chunk_from_strings("LASt", "I am the last chunk").unwrap(),
+        ];
+        Png::from_chunks(chunks)
+    }
+
+    #[test]
+    fn test_png_from_chunks() {
+        let png = testing_png();
+        assert_eq!(png.chunks().len(), 3);
+    }
+
+    #[test]
+    fn test_png_append_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("TeSt", "Message").unwrap());
+        assert_eq!(png.chunks().len(), 4);
+        assert_eq!(png.chunk_by_type("TeSt").unwrap().data_as_string().unwrap(), "Message");
+    }
+
+    #[test]
+    fn test_png_remove_chunk() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("TeSt", "Message").unwrap());
+        let removed = png.remove_first_chunk("TeSt").unwrap();
+        assert_eq!(removed.data_as_string().unwrap(), "Message");
+        assert!(png.chunk_by_type("TeSt").is_none());
+    }
+
+    #[test]
+    fn test_png_insert_chunk_before() {
+        let mut png = testing_png();
+        png.insert_chunk_before("LASt", chunk_from_strings("TeSt", "Message").unwrap());
+        let position = png
+            .chunks()
+            .iter()
+            .position(|c| c.chunk_type().to_string() == "TeSt")
+            .unwrap();
+        assert_eq!(position, 2);
+    }
+
+    #[test]
+    fn test_png_from_bytes_round_trip() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("IEND", "").unwrap());
+        let bytes = png.as_bytes();
+
+        let parsed = Png::try_from(bytes.as_ref()).unwrap();
+        assert_eq!(parsed.as_bytes(), bytes);
+        assert_eq!(parsed.chunks().len(), png.chunks().len());
+    }
+
+    #[test]
+    fn test_png_from_bytes_stops_at_iend() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("IEND", "").unwrap());
+        let mut bytes = png.as_bytes();
+        // Trailing garbage after IEND must be ignored.
+        bytes.extend_from_slice(b"trailing garbage");
+
+        let parsed = Png::try_from(bytes.as_ref()).unwrap();
+        assert_eq!(parsed.chunks().len(), png.chunks().len());
+    }
+
+    #[test]
+    fn test_png_from_bytes_rejects_bad_header() {
+        let mut png = testing_png();
+        png.append_chunk(chunk_from_strings("IEND", "").unwrap());
+        let mut bytes = png.as_bytes();
+        bytes[0] = 0;
+        assert!(Png::try_from(bytes.as_ref()).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+use crate::{Error, Result};
+use std::convert::TryInto;
+
+/// Magic bytes identifying a chunk's data as a payload segment produced by
+/// [`split_payload`], as opposed to ordinary chunk data.
+const SEGMENT_MAGIC: [u8; 4] = *b"SEG0";
+
+/// Size, in bytes, of a segment's header: the magic, the total segment
+/// count, and the zero-based segment index, each a big-endian `u32`.
+const SEGMENT_HEADER_LEN: usize = 12;
+
+/// Split `data` into one or more chunks of type `chunk_type`, each carrying
+/// at most `max_segment_len` bytes of the payload behind a small fixed
+/// header (a magic, the total segment count, and the segment's index).
+///
+/// Scattering the segments among a PNG's other chunks, in any order, is
+/// fine: [`reassemble`] sorts them back into place by index.
+///
+/// # Examples
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use pmsg::{reassemble, split_payload, ChunkType};
+/// # use std::convert::TryFrom;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let chunk_type = ChunkType::try_from(*b"seGm")?;
+///     let payload = b"a secret message too big for one segment".to_vec();
+///
+///     let chunks = split_payload(chunk_type, &payload, 10)?;
+///     assert!(chunks.len() > 1);
+///     assert_eq!(reassemble(&chunks)?, payload);
+///     Ok(())
+/// # }
+/// ```
+pub fn split_payload(chunk_type: ChunkType, data: &[u8], max_segment_len: usize) -> Result<Vec<Chunk>> {
+    if max_segment_len == 0 {
+        return Err(Error::InvalidSegmentLength);
+    }
+
+    let segments: Vec<&[u8]> = if data.is_empty() {
+        vec![&[][..]]
+    } else {
+        data.chunks(max_segment_len).collect()
+    };
+    let count: u32 = segments.len().try_into()?;
+
+    segments
+        .into_iter()
+        .enumerate()
+        .map(|(index, segment)| {
+            let index: u32 = index.try_into()?;
+
+            let mut chunk_data = Vec::with_capacity(SEGMENT_HEADER_LEN + segment.len());
+            chunk_data.extend_from_slice(&SEGMENT_MAGIC);
+            chunk_data.extend_from_slice(&count.to_be_bytes());
+            chunk_data.extend_from_slice(&index.to_be_bytes());
+            chunk_data.extend_from_slice(segment);
+
+            Chunk::new(chunk_type, chunk_data)
+        })
+        .collect()
+}
+
+/// Reassemble the payload previously split by [`split_payload`] out of
+/// `chunks`, ignoring any chunk whose data doesn't start with the segment
+/// magic.
+///
+/// Returns an error if no segments are found, if the segments disagree on
+/// the total segment count, or if an index is missing or duplicated once the
+/// segments are sorted back into order.
+pub fn reassemble(chunks: &[Chunk]) -> Result<Vec<u8>> {
+    let mut total: Option<u32> = None;
+    let mut segments: Vec<(u32, &[u8])> = Vec::new();
+
+    for chunk in chunks {
+        let data = chunk.data();
+        if data.len() < SEGMENT_HEADER_LEN || data[..4] != SEGMENT_MAGIC {
+            continue;
+        }
+
+        let count = u32::from_be_bytes(data[4..8].try_into().unwrap());
+        let index = u32::from_be_bytes(data[8..12].try_into().unwrap());
+
+        match total {
+            None => total = Some(count),
+            Some(expected) if expected != count => return Err(Error::SegmentCountMismatch),
+            Some(_) => {}
+        }
+
+        segments.push((index, &data[SEGMENT_HEADER_LEN..]));
+    }
+
+    let total = total.ok_or(Error::NoSegmentsFound)?;
+    if segments.len() as u32 != total {
+        return Err(Error::MissingSegment);
+    }
+
+    segments.sort_by_key(|(index, _)| *index);
+    for (expected, (index, _)) in segments.iter().enumerate() {
+        if *index != expected as u32 {
+            return Err(Error::MissingSegment);
+        }
+    }
+
+    Ok(segments
+        .into_iter()
+        .flat_map(|(_, data)| data.iter().copied())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    fn segment_chunk_type() -> ChunkType {
+        ChunkType::try_from(*b"seGm").unwrap()
+    }
+
+    #[test]
+    fn test_split_payload_fits_in_one_segment() {
+        let payload = b"short".to_vec();
+        let chunks = split_payload(segment_chunk_type(), &payload, 100).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(reassemble(&chunks).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_split_payload_splits_across_segments() {
+        let payload: Vec<u8> = (0..250).map(|i| i as u8).collect();
+        let chunks = split_payload(segment_chunk_type(), &payload, 32).unwrap();
+        assert_eq!(chunks.len(), 8);
+        assert_eq!(reassemble(&chunks).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_reassemble_is_order_independent() {
+        let payload: Vec<u8> = (0..250).map(|i| i as u8).collect();
+        let mut chunks = split_payload(segment_chunk_type(), &payload, 32).unwrap();
+        chunks.reverse();
+        assert_eq!(reassemble(&chunks).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_reassemble_ignores_unrelated_chunks() {
+        let payload = b"hidden".to_vec();
+        let mut chunks = split_payload(segment_chunk_type(), &payload, 100).unwrap();
+        chunks.insert(0, Chunk::new(ChunkType::try_from(*b"IHDR").unwrap(), vec![1, 2, 3]).unwrap());
+        assert_eq!(reassemble(&chunks).unwrap(), payload);
+    }
+
+    #[test]
+    fn test_reassemble_errors_on_missing_segment() {
+        let payload: Vec<u8> = (0..250).map(|i| i as u8).collect();
+        let mut chunks = split_payload(segment_chunk_type(), &payload, 32).unwrap();
+        chunks.remove(3);
+        assert!(reassemble(&chunks).is_err());
+    }
+
+    #[test]
+    fn test_reassemble_errors_on_duplicate_index() {
+        let payload: Vec<u8> = (0..250).map(|i| i as u8).collect();
+        let mut chunks = split_payload(segment_chunk_type(), &payload, 32).unwrap();
+        let duplicate = chunks[0].as_bytes();
+        chunks.push(Chunk::try_from(duplicate.as_ref()).unwrap());
+        chunks.remove(chunks.len() - 2);
+        assert!(reassemble(&chunks).is_err());
+    }
+
+    #[test]
+    fn test_reassemble_errors_when_no_segments_present() {
+        let chunks = vec![Chunk::new(ChunkType::try_from(*b"IHDR").unwrap(), vec![1, 2, 3]).unwrap()];
+        assert!(reassemble(&chunks).is_err());
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{Error, Result};
+
+const TAG_FALSE: u8 = 0x00;
+const TAG_TRUE: u8 = 0x01;
+const TAG_INT: u8 = 0x02;
+const TAG_BYTES: u8 = 0x03;
+const TAG_STR: u8 = 0x04;
+const TAG_LIST_START: u8 = 0x05;
+const TAG_LIST_END: u8 = 0x06;
+
+/// A self-describing value that can be packed into a chunk's data and
+/// unpacked again, for embedding structured metadata rather than only plain
+/// UTF-8 strings.
+///
+/// Each value is encoded as a one-byte tag identifying its kind, followed by
+/// whatever payload that kind needs: booleans are just the tag, integers are
+/// big-endian with a one-byte length prefix, byte strings and UTF-8 strings
+/// carry a varint byte count, and lists are bracketed by a start and an end
+/// tag so they can nest.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Int(i64),
+    Bytes(Vec<u8>),
+    Str(String),
+    List(Vec<Value>),
+}
+
+/// Encode `value` using the packed tagged encoding described on [`Value`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use pmsg::value::{decode, encode, Value};
+/// let value = Value::List(vec![Value::Int(-7), Value::Str("hi".into())]);
+/// assert_eq!(decode(&encode(&value)).unwrap(), value);
+/// ```
+pub fn encode(value: &Value) -> Vec<u8> {
+    let mut buf = Vec::new();
+    encode_into(value, &mut buf);
+    buf
+}
+
+fn encode_into(value: &Value, buf: &mut Vec<u8>) {
+    match value {
+        Value::Bool(false) => buf.push(TAG_FALSE),
+        Value::Bool(true) => buf.push(TAG_TRUE),
+        Value::Int(n) => {
+            buf.push(TAG_INT);
+            buf.push(8);
+            buf.extend_from_slice(&n.to_be_bytes());
+        }
+        Value::Bytes(data) => {
+            buf.push(TAG_BYTES);
+            write_varint(buf, data.len() as u64);
+            buf.extend_from_slice(data);
+        }
+        Value::Str(s) => {
+            buf.push(TAG_STR);
+            write_varint(buf, s.len() as u64);
+            buf.extend_from_slice(s.as_bytes());
+        }
+        Value::List(items) => {
+            buf.push(TAG_LIST_START);
+            for item in items {
+                encode_into(item, buf);
+            }
+            buf.push(TAG_LIST_END);
+        }
+    }
+}
+
+/// Decode a single [`Value`] out of `data`.
+///
+/// Returns an error if the tag is unknown or the data is truncated before
+/// the value (or, for a list, its closing tag) is fully read.
+pub fn decode(data: &[u8]) -> Result<Value> {
+    let (value, consumed) = decode_one(data)?;
+    if consumed != data.len() {
+        return Err(Error::TrailingValueBytes);
+    }
+    Ok(value)
+}
+
+fn decode_one(data: &[u8]) -> Result<(Value, usize)> {
+    let tag = *data.first().ok_or(Error::TruncatedValue)?;
+    match tag {
+        TAG_FALSE => Ok((Value::Bool(false), 1)),
+        TAG_TRUE => Ok((Value::Bool(true), 1)),
+        TAG_INT => {
+            let len = *data.get(1).ok_or(Error::TruncatedValue)? as usize;
+            if len > 8 {
+                return Err(Error::InvalidValueEncoding);
+            }
+            let start = 2;
+            let end = start + len;
+            let bytes = data.get(start..end).ok_or(Error::TruncatedValue)?;
+
+            let mut full = [0u8; 8];
+            full[8 - len..].copy_from_slice(bytes);
+            Ok((Value::Int(i64::from_be_bytes(full)), end))
+        }
+        TAG_BYTES | TAG_STR => {
+            let (len, varint_len) = read_varint(&data[1..])?;
+            let start = 1 + varint_len;
+            // Compare against the remaining bytes before casting `len` to
+            // `usize`, so a huge varint can't wrap around on 32-bit targets
+            // and be mistaken for a small, in-bounds length.
+            if len > (data.len() - start) as u64 {
+                return Err(Error::TruncatedValue);
+            }
+            let end = start + len as usize;
+            let bytes = data.get(start..end).ok_or(Error::TruncatedValue)?;
+
+            if tag == TAG_BYTES {
+                Ok((Value::Bytes(bytes.to_vec()), end))
+            } else {
+                Ok((Value::Str(String::from_utf8(bytes.to_vec())?), end))
+            }
+        }
+        TAG_LIST_START => {
+            let mut items = Vec::new();
+            let mut pos = 1;
+            loop {
+                match data.get(pos) {
+                    Some(&TAG_LIST_END) => {
+                        pos += 1;
+                        break;
+                    }
+                    Some(_) => {
+                        let (item, consumed) = decode_one(&data[pos..])?;
+                        items.push(item);
+                        pos += consumed;
+                    }
+                    None => return Err(Error::TruncatedValue),
+                }
+            }
+            Ok((Value::List(items), pos))
+        }
+        _ => Err(Error::UnknownValueTag),
+    }
+}
+
+/// Write `value` as a LEB128-style varint: seven bits per byte, the high bit
+/// set on every byte but the last.
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buf.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// Read a varint from the front of `data`, returning the value and the
+/// number of bytes it occupied.
+fn read_varint(data: &[u8]) -> Result<(u64, usize)> {
+    let mut result: u64 = 0;
+    let mut shift = 0u32;
+
+    for (consumed, &byte) in data.iter().enumerate() {
+        if shift >= 64 {
+            return Err(Error::InvalidValueEncoding);
+        }
+        result |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Ok((result, consumed + 1));
+        }
+        shift += 7;
+    }
+
+    Err(Error::TruncatedValue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bool_round_trip() {
+        assert_eq!(decode(&encode(&Value::Bool(true))).unwrap(), Value::Bool(true));
+        assert_eq!(decode(&encode(&Value::Bool(false))).unwrap(), Value::Bool(false));
+    }
+
+    #[test]
+    fn test_int_round_trip() {
+        for n in [0, 1, -1, i64::MAX, i64::MIN] {
+            assert_eq!(decode(&encode(&Value::Int(n))).unwrap(), Value::Int(n));
+        }
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let value = Value::Bytes(vec![0, 1, 2, 255]);
+        assert_eq!(decode(&encode(&value)).unwrap(), value);
+    }
+
+    #[test]
+    fn test_str_round_trip() {
+        let value = Value::Str("hidden message".to_string());
+        assert_eq!(decode(&encode(&value)).unwrap(), value);
+    }
+
+    #[test]
+    fn test_long_str_round_trip_exercises_multi_byte_varint() {
+        let value = Value::Str("x".repeat(200));
+        assert_eq!(decode(&encode(&value)).unwrap(), value);
+    }
+
+    #[test]
+    fn test_nested_list_round_trip() {
+        let value = Value::List(vec![
+            Value::Int(42),
+            Value::List(vec![Value::Bool(true), Value::Str("nested".into())]),
+            Value::Bytes(vec![9, 9, 9]),
+        ]);
+        assert_eq!(decode(&encode(&value)).unwrap(), value);
+    }
+
+    #[test]
+    fn test_decode_errors_on_unknown_tag() {
+        assert!(decode(&[0xAA]).is_err());
+    }
+
+    #[test]
+    fn test_decode_errors_on_truncated_list() {
+        let mut bytes = encode(&Value::List(vec![Value::Int(1)]));
+        bytes.pop(); // drop the closing TAG_LIST_END
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_decode_errors_on_truncated_string() {
+        let mut bytes = encode(&Value::Str("hello".into()));
+        bytes.truncate(bytes.len() - 2);
+        assert!(decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_decode_rejects_overflowing_length_without_panicking() {
+        let bytes = [
+            TAG_STR, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F,
+        ];
+        assert!(decode(&bytes).is_err());
+    }
+}